@@ -1,9 +1,12 @@
 use {
-    csv::{Reader, StringRecord, StringRecordsIter, Trim},
+    csv::{ByteRecord, Position, Reader, ReaderBuilder, StringRecord, StringRecordsIter, Terminator, Trim},
     derive_more::Display,
-    std::{fs::File, io::Read, path::Path},
+    serde::de::DeserializeOwned,
+    std::{collections::{HashMap, HashSet}, fs::File, io::{Read, Seek}, marker::PhantomData, path::Path},
 };
 
+pub mod schema;
+
 /// CSV-reader struct.
 ///
 /// # Examples
@@ -117,8 +120,9 @@ use {
 /// ```
 pub struct CsvReader<R: Read, H>
 {
-    reader: Reader<R>,
+    pub(crate) reader: Reader<R>,
     header_indexer: H,
+    pub(crate) headers: StringRecord,
 }
 
 #[derive(Display, Debug)]
@@ -129,19 +133,205 @@ pub enum CsvReaderCreationError<E>
     HeaderIndexerBuilderError(E),
 }
 
-impl<H> CsvReader<File, H>
+/// Builder for [`CsvReader`] exposing the full configuration surface of `csv::ReaderBuilder`.
+///
+/// # Examples
+///
+/// ```rust
+/// use {
+///     csv::StringRecord,
+///     std::io::BufReader,
+///     csv_miri_bug::{CsvReaderBuilder, CsvRowParser},
+/// };
+///
+/// struct HeaderIndexer {
+///     x_idx: usize,
+/// }
+///
+/// struct Parser;
+///
+/// impl CsvRowParser for Parser
+/// {
+///     type HeaderIndexer = HeaderIndexer;
+///     type R = f64;
+///     type E = std::num::ParseFloatError;
+///
+///     fn parse_row(
+///         &mut self,
+///         header_indexer: &Self::HeaderIndexer,
+///         row: StringRecord) -> Result<Self::R, Self::E>
+///     {
+///         row.get(header_indexer.x_idx).unwrap().parse()
+///     }
+/// }
+///
+/// const TSV_FILE: &str = "X\tY\r\n10.2\t11\r\n1\t12\r\n";
+///
+/// let reader = BufReader::new(TSV_FILE.as_bytes());
+/// let mut reader = CsvReaderBuilder::new()
+///     .delimiter(b'\t')
+///     .terminator(csv::Terminator::CRLF)
+///     .build_from_reader::<_, _, std::convert::Infallible>(
+///         |_: &StringRecord| Ok(HeaderIndexer { x_idx: 0 }),
+///         reader,
+///     )
+///     .unwrap();
+/// let mut reader = reader.with_parser(Parser);
+///
+/// assert_eq!(reader.next().unwrap().unwrap(), 10.2);
+/// assert_eq!(reader.next().unwrap().unwrap(), 1.0);
+/// assert!(reader.next().is_none());
+/// ```
+pub struct CsvReaderBuilder
 {
-    /// Creates a new instance of [`CsvReader`] from `path`.
-    pub fn new_from_path<E>(
+    has_headers: bool,
+    delimiter: u8,
+    terminator: Terminator,
+    quote: u8,
+    quoting: bool,
+    escape: Option<u8>,
+    double_quote: bool,
+    comment: Option<u8>,
+    trim: Trim,
+    flexible: bool,
+    buffer_capacity: usize,
+}
+
+impl Default for CsvReaderBuilder
+{
+    fn default() -> Self
+    {
+        Self {
+            has_headers: true,
+            delimiter: b',',
+            terminator: Terminator::CRLF,
+            quote: b'"',
+            quoting: true,
+            escape: None,
+            double_quote: true,
+            comment: None,
+            trim: Trim::All,
+            flexible: false,
+            buffer_capacity: 8 * (1 << 10),
+        }
+    }
+}
+
+impl CsvReaderBuilder
+{
+    /// Creates a new [`CsvReaderBuilder`] with the same defaults as `csv::ReaderBuilder`, except
+    /// that `trim` is [`Trim::All`], matching the crate's original hard-coded constructors.
+    pub fn new() -> Self
+    {
+        Self::default()
+    }
+
+    /// Whether the first record is treated as a header record. Defaults to `true`.
+    pub fn has_headers(mut self, yes: bool) -> Self
+    {
+        self.has_headers = yes;
+        self
+    }
+
+    /// The field delimiter. Defaults to `,`.
+    pub fn delimiter(mut self, delimiter: u8) -> Self
+    {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// The record terminator. Defaults to [`Terminator::CRLF`], which accepts both `\r\n` and
+    /// `\n`.
+    pub fn terminator(mut self, terminator: Terminator) -> Self
+    {
+        self.terminator = terminator;
+        self
+    }
+
+    /// The quote character. Defaults to `"`.
+    pub fn quote(mut self, quote: u8) -> Self
+    {
+        self.quote = quote;
+        self
+    }
+
+    /// Whether quoting is enabled at all. Defaults to `true`.
+    pub fn quoting(mut self, yes: bool) -> Self
+    {
+        self.quoting = yes;
+        self
+    }
+
+    /// The escape character used when `double_quote` is disabled. Defaults to `None`.
+    pub fn escape(mut self, escape: Option<u8>) -> Self
+    {
+        self.escape = escape;
+        self
+    }
+
+    /// Whether two consecutive quote characters escape a quote inside a quoted field. Defaults
+    /// to `true`.
+    pub fn double_quote(mut self, yes: bool) -> Self
+    {
+        self.double_quote = yes;
+        self
+    }
+
+    /// The comment character. Rows beginning with this byte are skipped entirely. Defaults to
+    /// `None`.
+    pub fn comment(mut self, comment: Option<u8>) -> Self
+    {
+        self.comment = comment;
+        self
+    }
+
+    /// Whether to trim leading/trailing whitespace from fields, headers, or both. Defaults to
+    /// [`Trim::All`].
+    pub fn trim(mut self, trim: Trim) -> Self
+    {
+        self.trim = trim;
+        self
+    }
+
+    /// Whether rows are allowed to have a varying number of fields. Defaults to `false`.
+    pub fn flexible(mut self, yes: bool) -> Self
+    {
+        self.flexible = yes;
+        self
+    }
+
+    /// The internal buffer capacity, in bytes. Defaults to 8 KiB.
+    pub fn buffer_capacity(mut self, capacity: usize) -> Self
+    {
+        self.buffer_capacity = capacity;
+        self
+    }
+
+    fn into_inner_builder(self) -> ReaderBuilder
+    {
+        let mut builder = ReaderBuilder::new();
+        builder
+            .has_headers(self.has_headers)
+            .delimiter(self.delimiter)
+            .terminator(self.terminator)
+            .quote(self.quote)
+            .quoting(self.quoting)
+            .escape(self.escape)
+            .double_quote(self.double_quote)
+            .comment(self.comment)
+            .trim(self.trim)
+            .flexible(self.flexible)
+            .buffer_capacity(self.buffer_capacity);
+        builder
+    }
+
+    /// Builds a [`CsvReader`] reading from `path`.
+    pub fn build_from_path<H, E>(
+        self,
         header_indexer_builder: impl FnOnce(&StringRecord) -> Result<H, E>,
-        path: impl AsRef<Path>,
-        delimiter: char) -> Result<Self, CsvReaderCreationError<E>>
+        path: impl AsRef<Path>) -> Result<CsvReader<File, H>, CsvReaderCreationError<E>>
     {
-        let mut reader = csv::ReaderBuilder::new()
-            .has_headers(true)
-            .delimiter(delimiter as u8)
-            .comment(b'#'.into())
-            .trim(Trim::All)
+        let mut reader = self.into_inner_builder()
             .from_path(path)
             .map_err(CsvReaderCreationError::CsvError)?;
 
@@ -150,27 +340,25 @@ impl<H> CsvReader<File, H>
 
         let header_indexer = header_indexer_builder(headers)
             .map_err(CsvReaderCreationError::HeaderIndexerBuilderError)?;
+        let headers = headers.clone();
 
-        let result = Self {
+        let result = CsvReader {
             reader,
             header_indexer,
+            headers,
         };
         Ok(result)
     }
-}
 
-impl<R: Read, H> CsvReader<R, H>
-{
-    /// Creates a new instance of [`CsvReader`] from `reader`.
-    pub fn new_from_reader<E>(
+    /// Builds a [`CsvReader`] reading from `reader`.
+    pub fn build_from_reader<R, H, E>(
+        self,
         header_indexer_builder: impl FnOnce(&StringRecord) -> Result<H, E>,
-        reader: R,
-        delimiter: char) -> Result<Self, CsvReaderCreationError<E>>
+        reader: R) -> Result<CsvReader<R, H>, CsvReaderCreationError<E>>
+        where
+            R: Read,
     {
-        let mut reader = csv::ReaderBuilder::new()
-            .has_headers(true)
-            .delimiter(delimiter as u8)
-            .trim(Trim::All)
+        let mut reader = self.into_inner_builder()
             .from_reader(reader);
 
         let headers = reader.headers()
@@ -178,26 +366,540 @@ impl<R: Read, H> CsvReader<R, H>
 
         let header_indexer = header_indexer_builder(headers)
             .map_err(CsvReaderCreationError::HeaderIndexerBuilderError)?;
+        let headers = headers.clone();
 
-        let result = Self {
+        let result = CsvReader {
             reader,
             header_indexer,
+            headers,
         };
         Ok(result)
     }
+}
+
+impl<H> CsvReader<File, H>
+{
+    /// Creates a new instance of [`CsvReader`] from `path`.
+    ///
+    /// This is a thin wrapper over [`CsvReaderBuilder`], additionally defaulting to a `#`
+    /// comment char (matching this constructor's historical behavior) and exposing only the
+    /// delimiter for configuration. Use [`CsvReaderBuilder::build_from_path`] directly to
+    /// configure the comment character, quoting, terminator, or any other option the underlying
+    /// `csv::ReaderBuilder` supports.
+    pub fn new_from_path<E>(
+        header_indexer_builder: impl FnOnce(&StringRecord) -> Result<H, E>,
+        path: impl AsRef<Path>,
+        delimiter: char) -> Result<Self, CsvReaderCreationError<E>>
+    {
+        CsvReaderBuilder::new()
+            .delimiter(delimiter as u8)
+            .comment(Some(b'#'))
+            .build_from_path(header_indexer_builder, path)
+    }
+}
+
+impl<R: Read, H> CsvReader<R, H>
+{
+    /// Creates a new instance of [`CsvReader`] from `reader`.
+    ///
+    /// This is a thin wrapper over [`CsvReaderBuilder`] using its defaults, exposing only the
+    /// delimiter for configuration. Use [`CsvReaderBuilder::build_from_reader`] directly to
+    /// configure the comment character, quoting, terminator, or any other option the underlying
+    /// `csv::ReaderBuilder` supports.
+    pub fn new_from_reader<E>(
+        header_indexer_builder: impl FnOnce(&StringRecord) -> Result<H, E>,
+        reader: R,
+        delimiter: char) -> Result<Self, CsvReaderCreationError<E>>
+    {
+        CsvReaderBuilder::new()
+            .delimiter(delimiter as u8)
+            .build_from_reader(header_indexer_builder, reader)
+    }
 
     /// Creates a new instance of [`CsvRowReader`].
     pub fn with_parser<P>(&mut self, row_parser: P) -> CsvRowReader<R, P>
         where
             P: CsvRowParser<HeaderIndexer=H>
     {
-        let Self { reader, header_indexer } = self;
+        let Self { reader, header_indexer, .. } = self;
         CsvRowReader {
             row_reader: reader.records(),
             header_indexer,
             row_parser,
         }
     }
+
+    /// Creates a new instance of [`CsvByteRowReader`].
+    ///
+    /// Unlike [`with_parser`](Self::with_parser), this drives the reader through
+    /// [`csv::Reader::read_byte_record`] into a single, reused [`ByteRecord`] buffer instead of
+    /// the allocating [`StringRecordsIter`], skipping UTF-8 validation entirely. Prefer this for
+    /// high-throughput parsing of large files where [`ByteCsvRowParser`] only needs to look at
+    /// raw bytes.
+    pub fn with_byte_parser<P>(&mut self, row_parser: P) -> CsvByteRowReader<R, P>
+        where
+            P: ByteCsvRowParser<HeaderIndexer=H>
+    {
+        let Self { reader, header_indexer, .. } = self;
+        CsvByteRowReader {
+            reader,
+            record: ByteRecord::new(),
+            header_indexer,
+            row_parser,
+        }
+    }
+
+    /// Creates a new instance of [`CsvRowReader`] that deserializes rows into `T` via serde,
+    /// without requiring a hand-written [`CsvRowParser`].
+    ///
+    /// The [`SerdeRowParser`] is driven by the header record captured at construction time, so
+    /// this works regardless of what `H` the reader was built with.
+    pub fn with_serde_parser<T>(&mut self) -> CsvRowReader<R, SerdeRowParser<T>>
+        where
+            T: DeserializeOwned
+    {
+        let Self { reader, headers, .. } = self;
+        CsvRowReader {
+            row_reader: reader.records(),
+            header_indexer: headers,
+            row_parser: SerdeRowParser::new(),
+        }
+    }
+
+    /// Hash-joins `self` (the left side) with `other` (the right side) on the key columns
+    /// resolved by `left_keys`/`right_keys`, consuming both readers.
+    ///
+    /// `other` is fully drained up front into a `HashMap` keyed by its `right_keys` columns;
+    /// `self` is then streamed lazily, looking up matches for each row. When `keep_duplicate_columns`
+    /// is `false`, the `right_keys` columns are dropped from the merged rows since they
+    /// duplicate the `left_keys` values.
+    pub fn join<R2, H2>(
+        self,
+        other: CsvReader<R2, H2>,
+        left_keys: impl FnOnce(&H) -> Vec<usize>,
+        right_keys: impl FnOnce(&H2) -> Vec<usize>,
+        kind: JoinKind,
+        keep_duplicate_columns: bool) -> csv::Result<CsvJoin<R>>
+        where
+            R2: Read,
+    {
+        let left_keys = left_keys(&self.header_indexer);
+        let right_keys = right_keys(&other.header_indexer);
+        let (left_keys, right_keys) = dedupe_join_keys(left_keys, right_keys);
+        let right_width = other.headers.len();
+
+        let Self { reader: left_reader, .. } = self;
+        let CsvReader { reader: right_reader, .. } = other;
+
+        let mut right_index: HashMap<Vec<u8>, Vec<StringRecord>> = HashMap::new();
+        for row in right_reader.into_records() {
+            let row = row?;
+            let key = encode_join_key(&row, &right_keys);
+            right_index.entry(key).or_default().push(row);
+        }
+
+        let result = CsvJoin {
+            left: left_reader.into_records(),
+            left_keys,
+            right_keys,
+            right_index,
+            kind,
+            keep_duplicate_columns,
+            right_width,
+            pending: Vec::new().into_iter(),
+        };
+        Ok(result)
+    }
+
+    /// Parses every row using `row_parser` across `num_threads` worker threads, preserving input
+    /// order in the returned iterator.
+    ///
+    /// Rows are read sequentially off `self` into bounded batches of `batch_size`, which are then
+    /// distributed round-robin across the worker pool; each worker runs its own clone of
+    /// `row_parser`. This turns CPU-bound row parsing from one core into `num_threads`, at the
+    /// cost of buffering the whole reader's output before yielding the first item.
+    pub fn par_with_parser<P>(
+        &mut self,
+        row_parser: P,
+        batch_size: usize,
+        num_threads: usize) -> impl Iterator<Item=Result<P::R, CsvRowReaderError<P::E>>>
+        where
+            P: CsvRowParser<HeaderIndexer=H> + Clone + Send,
+            P::R: Send,
+            P::E: Send,
+            H: Sync,
+    {
+        let batch_size = batch_size.max(1);
+        let num_threads = num_threads.max(1);
+
+        let Self { reader, header_indexer, .. } = self;
+        let header_indexer: &H = header_indexer;
+
+        let mut batches = Vec::new();
+        let mut current = Vec::with_capacity(batch_size);
+        for row in reader.records() {
+            current.push(row);
+            if current.len() == batch_size {
+                batches.push(std::mem::replace(&mut current, Vec::with_capacity(batch_size)));
+            }
+        }
+        if !current.is_empty() {
+            batches.push(current);
+        }
+
+        let mut worker_groups = distribute_round_robin(batches, num_threads);
+
+        let mut results: OrderedBatches<P::R, P::E> =
+            std::thread::scope(|scope| {
+                let handles: Vec<_> = worker_groups.drain(..)
+                    .map(|worker_batches| {
+                        let mut row_parser = row_parser.clone();
+                        scope.spawn(move || {
+                            worker_batches.into_iter()
+                                .enumerate()
+                                .map(|(slot, batch)| {
+                                    let parsed = batch.into_iter()
+                                        .map(|row| match row {
+                                            Ok(row) => row_parser.parse_row(header_indexer, row)
+                                                .map_err(CsvRowReaderError::RowParserError),
+                                            Err(err) => Err(CsvRowReaderError::CsvRecordError(err)),
+                                        })
+                                        .collect();
+                                    (slot, parsed)
+                                })
+                                .collect::<Vec<_>>()
+                        })
+                    })
+                    .collect();
+
+                handles.into_iter()
+                    .enumerate()
+                    .flat_map(|(thread_idx, handle)| {
+                        handle.join()
+                            .expect("par_with_parser worker thread panicked")
+                            .into_iter()
+                            .map(move |(slot, parsed)| (slot * num_threads + thread_idx, parsed))
+                    })
+                    .collect()
+            });
+
+        results.sort_by_key(|(batch_idx, _)| *batch_idx);
+        results.into_iter().flat_map(|(_, batch)| batch)
+    }
+}
+
+/// One worker's parsed output for a single batch, as produced inside [`CsvReader::par_with_parser`].
+type ParBatch<R, E> = Vec<Result<R, CsvRowReaderError<E>>>;
+
+/// Parsed batches tagged with their original position in the input, as produced inside
+/// [`CsvReader::par_with_parser`] before being sorted back into order.
+type OrderedBatches<R, E> = Vec<(usize, ParBatch<R, E>)>;
+
+fn distribute_round_robin<T>(items: Vec<T>, num_groups: usize) -> Vec<Vec<T>>
+{
+    let mut groups: Vec<Vec<T>> = (0..num_groups).map(|_| Vec::new()).collect();
+    for (idx, item) in items.into_iter().enumerate() {
+        groups[idx % num_groups].push(item);
+    }
+    groups
+}
+
+/// The kind of hash join performed by [`CsvJoin`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinKind
+{
+    /// Emit only left rows that have at least one matching right row.
+    Inner,
+    /// Emit every left row, padding with empty fields when there is no matching right row.
+    LeftOuter,
+}
+
+/// Drops positions where `right_keys` repeats an index already seen, keeping the corresponding
+/// `left_keys` entry in lockstep so the two lists stay positionally aligned (`left_keys[i]` and
+/// `right_keys[i]` must refer to the same logical key column for [`encode_join_key`] to agree
+/// between the two sides). Without this, a caller-supplied composite key that repeats a right-side
+/// column would make [`merge_join_row`]/[`pad_join_row`] disagree on how many right columns are
+/// dropped between matched and unmatched rows.
+fn dedupe_join_keys(left_keys: Vec<usize>, right_keys: Vec<usize>) -> (Vec<usize>, Vec<usize>)
+{
+    let mut seen = HashSet::new();
+    left_keys.into_iter()
+        .zip(right_keys)
+        .filter(|(_, right_idx)| seen.insert(*right_idx))
+        .unzip()
+}
+
+fn encode_join_key(record: &StringRecord, indices: &[usize]) -> Vec<u8>
+{
+    let mut key = Vec::new();
+    for &idx in indices {
+        let field = record.get(idx).unwrap_or("").as_bytes();
+        key.extend_from_slice(&(field.len() as u32).to_be_bytes());
+        key.extend_from_slice(field);
+    }
+    key
+}
+
+fn merge_join_row(
+    left: &StringRecord,
+    right: &StringRecord,
+    right_keys: &[usize],
+    keep_duplicate_columns: bool) -> StringRecord
+{
+    let mut merged = StringRecord::new();
+    for field in left.iter() {
+        merged.push_field(field);
+    }
+    for (idx, field) in right.iter().enumerate() {
+        if keep_duplicate_columns || !right_keys.contains(&idx) {
+            merged.push_field(field);
+        }
+    }
+    merged
+}
+
+fn pad_join_row(
+    left: &StringRecord,
+    right_width: usize,
+    right_keys: &[usize],
+    keep_duplicate_columns: bool) -> StringRecord
+{
+    let mut merged = StringRecord::new();
+    for field in left.iter() {
+        merged.push_field(field);
+    }
+    let padding = if keep_duplicate_columns {
+        right_width
+    } else {
+        right_width.saturating_sub(right_keys.len())
+    };
+    for _ in 0..padding {
+        merged.push_field("");
+    }
+    merged
+}
+
+/// Iterator produced by [`CsvReader::join`], yielding merged rows from a hash join of the left
+/// reader against the right reader's pre-built index.
+///
+/// # Examples
+///
+/// ```rust
+/// use csv_miri_bug::{CsvReader, JoinKind};
+///
+/// const LEFT_CSV: &str = "id,name\n1,alice\n2,bob\n3,carol\n";
+/// const RIGHT_CSV: &str = "id,age\n1,30\n2,25\n";
+///
+/// let left = CsvReader::new_from_reader(
+///     |_| Ok::<_, std::convert::Infallible>(()),
+///     LEFT_CSV.as_bytes(),
+///     ',',
+/// ).unwrap();
+/// let right = CsvReader::new_from_reader(
+///     |_| Ok::<_, std::convert::Infallible>(()),
+///     RIGHT_CSV.as_bytes(),
+///     ',',
+/// ).unwrap();
+///
+/// let rows: Vec<_> = left
+///     .join(right, |_| vec![0], |_| vec![0], JoinKind::LeftOuter, false)
+///     .unwrap()
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+///
+/// assert_eq!(rows[0].iter().collect::<Vec<_>>(), vec!["1", "alice", "30"]);
+/// assert_eq!(rows[1].iter().collect::<Vec<_>>(), vec!["2", "bob", "25"]);
+/// assert_eq!(rows[2].iter().collect::<Vec<_>>(), vec!["3", "carol", ""]);
+/// ```
+pub struct CsvJoin<R: Read>
+{
+    left: csv::StringRecordsIntoIter<R>,
+    left_keys: Vec<usize>,
+    right_keys: Vec<usize>,
+    right_index: HashMap<Vec<u8>, Vec<StringRecord>>,
+    kind: JoinKind,
+    keep_duplicate_columns: bool,
+    right_width: usize,
+    pending: std::vec::IntoIter<StringRecord>,
+}
+
+impl<R: Read> Iterator for CsvJoin<R>
+{
+    type Item = csv::Result<StringRecord>;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        loop {
+            if let Some(row) = self.pending.next() {
+                return Some(Ok(row));
+            }
+
+            let left_row = match self.left.next()? {
+                Ok(row) => row,
+                Err(err) => return Some(Err(err)),
+            };
+            let key = encode_join_key(&left_row, &self.left_keys);
+            let merged = match self.right_index.get(&key) {
+                Some(right_rows) => right_rows.iter()
+                    .map(|right_row| merge_join_row(
+                        &left_row,
+                        right_row,
+                        &self.right_keys,
+                        self.keep_duplicate_columns,
+                    ))
+                    .collect(),
+                None => match self.kind {
+                    JoinKind::Inner => Vec::new(),
+                    JoinKind::LeftOuter => vec![pad_join_row(
+                        &left_row,
+                        self.right_width,
+                        &self.right_keys,
+                        self.keep_duplicate_columns,
+                    )],
+                },
+            };
+            self.pending = merged.into_iter();
+        }
+    }
+}
+
+impl<R: Read + Seek, H> CsvReader<R, H>
+{
+    /// Seeks the underlying reader so that the next record read is the one at position `n` in
+    /// `index`.
+    pub fn seek_to_record(&mut self, index: &CsvIndex, n: usize) -> Result<(), CsvIndexSeekError>
+    {
+        let position = index.positions.get(n)
+            .ok_or(CsvIndexSeekError::RecordOutOfRange { index: n, len: index.positions.len() })?;
+        self.reader.seek(position.clone())
+            .map_err(CsvIndexSeekError::CsvError)
+    }
+}
+
+/// Byte-offset index over a [`CsvReader`]'s records, built by [`CsvIndex::build`].
+///
+/// Enables O(1) row lookup and slicing over multi-gigabyte CSVs, which the forward-only
+/// [`Iterator`] impls on [`CsvRowReader`] and [`CsvByteRowReader`] cannot provide on their own.
+///
+/// # Examples
+///
+/// ```rust
+/// use {
+///     std::io::Cursor,
+///     csv_miri_bug::{CsvIndex, CsvReader, CsvRowParser},
+/// };
+///
+/// struct HeaderIndexer;
+///
+/// struct Parser;
+///
+/// impl CsvRowParser for Parser
+/// {
+///     type HeaderIndexer = HeaderIndexer;
+///     type R = i64;
+///     type E = std::num::ParseIntError;
+///
+///     fn parse_row(
+///         &mut self,
+///         _header_indexer: &Self::HeaderIndexer,
+///         row: csv::StringRecord) -> Result<Self::R, Self::E>
+///     {
+///         row.get(0).unwrap().parse()
+///     }
+/// }
+///
+/// const CSV_FILE: &str = "N\n1\n2\n3\n";
+///
+/// let mut reader = CsvReader::new_from_reader(
+///     |_: &csv::StringRecord| Ok::<_, std::convert::Infallible>(HeaderIndexer),
+///     Cursor::new(CSV_FILE.as_bytes()),
+///     ',',
+/// ).unwrap();
+///
+/// let index = CsvIndex::build(&mut reader).unwrap();
+/// assert_eq!(index.len(), 3);
+///
+/// let mut parser = Parser;
+/// assert_eq!(index.get(&mut reader, &mut parser, 2).unwrap(), 3);
+/// assert_eq!(index.get(&mut reader, &mut parser, 0).unwrap(), 1);
+/// ```
+pub struct CsvIndex
+{
+    positions: Vec<Position>,
+}
+
+impl CsvIndex
+{
+    /// Scans `reader` once, recording the starting byte [`Position`] of every remaining record.
+    ///
+    /// The header record, already consumed when `reader` was constructed, is not included.
+    pub fn build<R, H>(reader: &mut CsvReader<R, H>) -> csv::Result<Self>
+        where
+            R: Read + Seek,
+    {
+        let mut positions = Vec::new();
+        let mut records = reader.reader.byte_records();
+        loop {
+            let position = records.reader().position().clone();
+            match records.next() {
+                Some(Ok(_)) => positions.push(position),
+                Some(Err(err)) => return Err(err),
+                None => break,
+            }
+        }
+        let result = Self { positions };
+        Ok(result)
+    }
+
+    /// The number of indexed records.
+    pub fn len(&self) -> usize
+    {
+        self.positions.len()
+    }
+
+    /// Whether the index holds no records.
+    pub fn is_empty(&self) -> bool
+    {
+        self.positions.is_empty()
+    }
+
+    /// Parses the record at position `n`, seeking directly to its byte offset instead of
+    /// reading every preceding record.
+    pub fn get<R, H, P>(
+        &self,
+        reader: &mut CsvReader<R, H>,
+        row_parser: &mut P,
+        n: usize) -> Result<P::R, CsvIndexGetError<P::E>>
+        where
+            R: Read + Seek,
+            P: CsvRowParser<HeaderIndexer=H>,
+    {
+        reader.seek_to_record(self, n)
+            .map_err(CsvIndexGetError::Seek)?;
+        let mut record = StringRecord::new();
+        reader.reader.read_record(&mut record)
+            .map_err(CsvIndexGetError::CsvError)?;
+        row_parser.parse_row(&reader.header_indexer, record)
+            .map_err(CsvIndexGetError::RowParserError)
+    }
+}
+
+#[derive(Display, Debug)]
+/// Error returned by [`CsvReader::seek_to_record`].
+pub enum CsvIndexSeekError
+{
+    CsvError(csv::Error),
+    #[display(fmt = "record {} out of range (len {})", index, len)]
+    RecordOutOfRange { index: usize, len: usize },
+}
+
+#[derive(Display, Debug)]
+/// Error returned by [`CsvIndex::get`].
+pub enum CsvIndexGetError<E>
+{
+    Seek(CsvIndexSeekError),
+    CsvError(csv::Error),
+    RowParserError(E),
 }
 
 /// CSV-row reader.
@@ -237,6 +939,41 @@ impl<'a, R: Read, P: CsvRowParser> Iterator for CsvRowReader<'a, R, P>
     }
 }
 
+/// CSV-row reader that parses directly off a reused [`ByteRecord`] buffer, skipping UTF-8
+/// validation and the per-row allocation [`CsvRowReader`] pays for.
+pub struct CsvByteRowReader<'a, R: Read, P: ByteCsvRowParser>
+{
+    reader: &'a mut Reader<R>,
+    record: ByteRecord,
+    header_indexer: &'a P::HeaderIndexer,
+    row_parser: P,
+}
+
+impl<'a, R: Read, P: ByteCsvRowParser> Iterator for CsvByteRowReader<'a, R, P>
+{
+    type Item = Result<P::R, CsvRowReaderError<P::E>>;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        let Self {
+            reader,
+            record,
+            header_indexer,
+            row_parser
+        } = self;
+        let has_more = match reader.read_byte_record(record) {
+            Ok(has_more) => has_more,
+            Err(err) => return Some(Err(CsvRowReaderError::CsvRecordError(err))),
+        };
+        if !has_more {
+            return None;
+        }
+        let result = row_parser.parse_row(header_indexer, record)
+            .map_err(CsvRowReaderError::RowParserError);
+        Some(result)
+    }
+}
+
 /// Trait that encapsulates CSV-row parsing logic.
 ///
 /// # Examples
@@ -364,14 +1101,156 @@ pub trait CsvRowParser
         row: StringRecord) -> Result<Self::R, Self::E>;
 }
 
+/// Trait that encapsulates zero-allocation, byte-oriented CSV-row parsing logic.
+///
+/// This mirrors [`CsvRowParser`] but receives a raw [`ByteRecord`] instead of a UTF-8-checked
+/// [`StringRecord`], letting parsers that only need to `memchr`/parse numeric fields skip UTF-8
+/// validation entirely. Column positions reported by a [`CsvRowParser::HeaderIndexer`]-compatible
+/// header indexer are unaffected, since both record types share the same column layout.
+///
+/// # Examples
+///
+/// ```rust
+/// use {
+///     csv::{ByteRecord, StringRecord},
+///     std::io::BufReader,
+///     csv_miri_bug::{ByteCsvRowParser, CsvReader},
+/// };
+///
+/// struct HeaderIndexer {
+///     x_idx: usize,
+/// }
+///
+/// impl HeaderIndexer
+/// {
+///     fn new(columns: &StringRecord) -> Result<HeaderIndexer, std::convert::Infallible>
+///     {
+///         let x_idx = columns.iter().position(|col| col == "X").unwrap();
+///         Ok(HeaderIndexer { x_idx })
+///     }
+/// }
+///
+/// struct Parser;
+///
+/// impl ByteCsvRowParser for Parser
+/// {
+///     type HeaderIndexer = HeaderIndexer;
+///     type R = f64;
+///     type E = std::num::ParseFloatError;
+///
+///     fn parse_row(
+///         &mut self,
+///         header_indexer: &Self::HeaderIndexer,
+///         row: &ByteRecord) -> Result<Self::R, Self::E>
+///     {
+///         let field = row.get(header_indexer.x_idx).unwrap();
+///         std::str::from_utf8(field).unwrap().parse()
+///     }
+/// }
+///
+/// const CSV_FILE: &str = "X,Y\n10.2,11\n1,12\n";
+///
+/// let reader = BufReader::new(CSV_FILE.as_bytes());
+/// let mut reader = CsvReader::new_from_reader(HeaderIndexer::new, reader, ',').unwrap();
+/// let mut reader = reader.with_byte_parser(Parser);
+///
+/// assert_eq!(reader.next().unwrap().unwrap(), 10.2);
+/// assert_eq!(reader.next().unwrap().unwrap(), 1.0);
+/// assert!(reader.next().is_none());
+/// ```
+pub trait ByteCsvRowParser
+{
+    /// Header indexer type.
+    type HeaderIndexer;
+    /// Row-parsing resulting type.
+    type R;
+    /// Row-parsing error type.
+    type E;
+
+    /// Parses single row.
+    fn parse_row(
+        &mut self,
+        header_indexer: &Self::HeaderIndexer,
+        row: &ByteRecord) -> Result<Self::R, Self::E>;
+}
+
+/// [`CsvRowParser`] that deserializes each row into `T` by field name via serde, using the
+/// reader's header record instead of a hand-written [`CsvRowParser::HeaderIndexer`].
+///
+/// Construct one through [`CsvReader::with_serde_parser`] rather than directly.
+///
+/// # Examples
+///
+/// ```rust
+/// use {
+///     serde::Deserialize,
+///     std::io::BufReader,
+///     csv_miri_bug::CsvReader,
+/// };
+///
+/// #[derive(Debug, PartialEq, Deserialize)]
+/// struct FromRow {
+///     #[serde(rename = "X")]
+///     x: f64,
+///     #[serde(rename = "Y")]
+///     y: i64,
+/// }
+///
+/// const CSV_FILE: &str = "X,Y\n10.2,11\n1,12\n";
+///
+/// let reader = BufReader::new(CSV_FILE.as_bytes());
+/// let mut reader = CsvReader::new_from_reader(|_| Ok::<_, std::convert::Infallible>(()), reader, ',').unwrap();
+/// let mut reader = reader.with_serde_parser::<FromRow>();
+///
+/// assert_eq!(reader.next().unwrap().unwrap(), FromRow { x: 10.2, y: 11 });
+/// assert_eq!(reader.next().unwrap().unwrap(), FromRow { x: 1.0, y: 12 });
+/// assert!(reader.next().is_none());
+/// ```
+pub struct SerdeRowParser<T>
+{
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> SerdeRowParser<T>
+{
+    /// Creates a new [`SerdeRowParser`].
+    pub fn new() -> Self
+    {
+        Self { _marker: PhantomData }
+    }
+}
+
+impl<T> Default for SerdeRowParser<T>
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<T: DeserializeOwned> CsvRowParser for SerdeRowParser<T>
+{
+    type HeaderIndexer = StringRecord;
+    type R = T;
+    type E = csv::Error;
+
+    fn parse_row(
+        &mut self,
+        header_indexer: &Self::HeaderIndexer,
+        row: StringRecord) -> Result<Self::R, Self::E>
+    {
+        row.deserialize(Some(header_indexer))
+    }
+}
+
 #[cfg(test)]
 mod tests
 {
     use {
-        csv::StringRecord,
+        csv::{ByteRecord, StringRecord},
         eyre::eyre,
-        std::io::BufReader,
-        super::{CsvReader, CsvRowParser},
+        std::io::{BufReader, Cursor},
+        super::{ByteCsvRowParser, CsvIndex, CsvIndexGetError, CsvIndexSeekError, CsvReader, CsvRowParser, JoinKind},
     };
 
     #[derive(Debug, PartialEq)]
@@ -416,6 +1295,7 @@ mod tests
         }
     }
 
+    #[derive(Clone)]
     struct Parser;
 
     impl CsvRowParser for Parser
@@ -475,4 +1355,211 @@ mod tests
         assert_eq!(reader.next().unwrap().unwrap(), FromRow { x: 1.0, y: 2 });
         assert!(reader.next().is_none())
     }
+
+    #[test]
+    pub fn new_from_path_skips_hash_comments()
+    {
+        let path = std::env::temp_dir()
+            .join(format!("csv_miri_bug_comment_test_{}.csv", std::process::id()));
+        std::fs::write(&path, "X,Y\n#10.2,11\n1,12\n").unwrap();
+
+        let mut reader = CsvReader::new_from_path(
+            HeaderIndexer::new,
+            &path,
+            ',',
+        )
+            .unwrap();
+        let mut reader = reader.with_parser(Parser);
+
+        assert_eq!(reader.next().unwrap().unwrap(), FromRow { x: 1.0, y: 12 });
+        assert!(reader.next().is_none());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    pub fn par_with_parser_preserves_input_order()
+    {
+        let rows: String = (0..23).map(|i| format!("{i}.0,{i}\n")).collect();
+        let csv_file = format!("X,Y\n{rows}");
+
+        let mut reader = CsvReader::new_from_reader(HeaderIndexer::new, csv_file.as_bytes(), ',').unwrap();
+
+        let parsed: Vec<FromRow> = reader
+            .par_with_parser(Parser, 4, 3)
+            .map(Result::unwrap)
+            .collect();
+
+        let expected: Vec<FromRow> = (0..23).map(|i| FromRow { x: i as f64, y: i as i64 }).collect();
+        assert_eq!(parsed, expected);
+    }
+
+    struct ByteParser;
+
+    impl ByteCsvRowParser for ByteParser
+    {
+        type HeaderIndexer = HeaderIndexer;
+        type R = f64;
+        type E = std::num::ParseFloatError;
+
+        fn parse_row(
+            &mut self,
+            header_indexer: &Self::HeaderIndexer,
+            row: &ByteRecord) -> Result<Self::R, Self::E>
+        {
+            let field = row.get(header_indexer.x_idx).unwrap();
+            std::str::from_utf8(field).unwrap().parse()
+        }
+    }
+
+    #[test]
+    pub fn with_byte_parser_parses_rows()
+    {
+        const CSV_FILE: &str = "X,Y\n10.2,11\n1,12\n";
+
+        let reader = BufReader::new(CSV_FILE.as_bytes());
+        let mut reader = CsvReader::new_from_reader(HeaderIndexer::new, reader, ',').unwrap();
+        let mut reader = reader.with_byte_parser(ByteParser);
+
+        assert_eq!(reader.next().unwrap().unwrap(), 10.2);
+        assert_eq!(reader.next().unwrap().unwrap(), 1.0);
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    pub fn csv_index_get_seeks_to_arbitrary_records()
+    {
+        const CSV_FILE: &str = "X,Y\n10.2,11\n1,12\n0,9\n";
+
+        let mut reader = CsvReader::new_from_reader(
+            HeaderIndexer::new,
+            Cursor::new(CSV_FILE.as_bytes()),
+            ',',
+        )
+            .unwrap();
+        let index = CsvIndex::build(&mut reader).unwrap();
+        assert_eq!(index.len(), 3);
+
+        let mut parser = Parser;
+        assert_eq!(index.get(&mut reader, &mut parser, 2).unwrap(), FromRow { x: 0.0, y: 9 });
+        assert_eq!(index.get(&mut reader, &mut parser, 0).unwrap(), FromRow { x: 10.2, y: 11 });
+
+        let err = index.get(&mut reader, &mut parser, 3).unwrap_err();
+        assert!(matches!(
+            err,
+            CsvIndexGetError::Seek(CsvIndexSeekError::RecordOutOfRange { index: 3, len: 3 })
+        ));
+    }
+
+    fn row(fields: &[&str]) -> Vec<String>
+    {
+        fields.iter().map(|field| field.to_string()).collect()
+    }
+
+    #[test]
+    pub fn join_inner_drops_unmatched_left_rows()
+    {
+        let left = CsvReader::new_from_reader(
+            |_: &StringRecord| Ok::<_, std::convert::Infallible>(()),
+            "id,name\n1,alice\n2,bob\n3,carol\n".as_bytes(),
+            ',',
+        )
+            .unwrap();
+        let right = CsvReader::new_from_reader(
+            |_: &StringRecord| Ok::<_, std::convert::Infallible>(()),
+            "id,age\n1,30\n2,25\n".as_bytes(),
+            ',',
+        )
+            .unwrap();
+
+        let rows: Vec<Vec<String>> = left
+            .join(right, |_| vec![0], |_| vec![0], JoinKind::Inner, false)
+            .unwrap()
+            .map(|row| row.unwrap().iter().map(str::to_string).collect())
+            .collect();
+
+        assert_eq!(rows, vec![row(&["1", "alice", "30"]), row(&["2", "bob", "25"])]);
+    }
+
+    #[test]
+    pub fn join_left_outer_pads_unmatched_left_rows()
+    {
+        let left = CsvReader::new_from_reader(
+            |_: &StringRecord| Ok::<_, std::convert::Infallible>(()),
+            "id,name\n1,alice\n2,bob\n3,carol\n".as_bytes(),
+            ',',
+        )
+            .unwrap();
+        let right = CsvReader::new_from_reader(
+            |_: &StringRecord| Ok::<_, std::convert::Infallible>(()),
+            "id,age\n1,30\n2,25\n".as_bytes(),
+            ',',
+        )
+            .unwrap();
+
+        let rows: Vec<Vec<String>> = left
+            .join(right, |_| vec![0], |_| vec![0], JoinKind::LeftOuter, false)
+            .unwrap()
+            .map(|row| row.unwrap().iter().map(str::to_string).collect())
+            .collect();
+
+        assert_eq!(
+            rows,
+            vec![
+                row(&["1", "alice", "30"]),
+                row(&["2", "bob", "25"]),
+                row(&["3", "carol", ""]),
+            ],
+        );
+    }
+
+    #[test]
+    pub fn join_keep_duplicate_columns_retains_right_key_field()
+    {
+        let left = CsvReader::new_from_reader(
+            |_: &StringRecord| Ok::<_, std::convert::Infallible>(()),
+            "id,name\n1,alice\n".as_bytes(),
+            ',',
+        )
+            .unwrap();
+        let right = CsvReader::new_from_reader(
+            |_: &StringRecord| Ok::<_, std::convert::Infallible>(()),
+            "id,age\n1,30\n".as_bytes(),
+            ',',
+        )
+            .unwrap();
+
+        let rows: Vec<Vec<String>> = left
+            .join(right, |_| vec![0], |_| vec![0], JoinKind::Inner, true)
+            .unwrap()
+            .map(|row| row.unwrap().iter().map(str::to_string).collect())
+            .collect();
+
+        assert_eq!(rows, vec![row(&["1", "alice", "1", "30"])]);
+    }
+
+    #[test]
+    pub fn join_deduplicates_repeated_right_key_columns()
+    {
+        let left = CsvReader::new_from_reader(
+            |_: &StringRecord| Ok::<_, std::convert::Infallible>(()),
+            "id,name\n1,alice\n2,bob\n".as_bytes(),
+            ',',
+        )
+            .unwrap();
+        let right = CsvReader::new_from_reader(
+            |_: &StringRecord| Ok::<_, std::convert::Infallible>(()),
+            "id,age\n1,30\n".as_bytes(),
+            ',',
+        )
+            .unwrap();
+
+        let rows: Vec<Vec<String>> = left
+            .join(right, |_| vec![0, 0], |_| vec![0, 0], JoinKind::LeftOuter, false)
+            .unwrap()
+            .map(|row| row.unwrap().iter().map(str::to_string).collect())
+            .collect();
+
+        assert_eq!(rows, vec![row(&["1", "alice", "30"]), row(&["2", "bob", ""])]);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,297 @@
+//! Automatic column type inference, producing a [`Schema`] and a [`DynamicRowParser`] driven by
+//! it.
+
+use {
+    crate::{CsvReader, CsvRowParser},
+    csv::StringRecord,
+    std::io::{Read, Seek},
+};
+
+/// A column's inferred type.
+///
+/// Inference starts every column at [`InferredType::Bool`] and widens it, in order, to
+/// [`InferredType::I64`], then [`InferredType::F64`], then [`InferredType::String`] as soon as a
+/// value is seen that the current type can't parse. `String` always accepts, so it's the final
+/// fallback for any column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum InferredType
+{
+    Bool,
+    I64,
+    F64,
+    String,
+}
+
+impl InferredType
+{
+    fn accepts(self, field: &str) -> bool
+    {
+        match self {
+            InferredType::Bool => field.parse::<bool>().is_ok(),
+            InferredType::I64 => field.parse::<i64>().is_ok(),
+            InferredType::F64 => field.parse::<f64>().is_ok(),
+            InferredType::String => true,
+        }
+    }
+
+    fn widened(self) -> Self
+    {
+        match self {
+            InferredType::Bool => InferredType::I64,
+            InferredType::I64 => InferredType::F64,
+            InferredType::F64 => InferredType::String,
+            InferredType::String => InferredType::String,
+        }
+    }
+}
+
+fn widen_for_field(mut ty: InferredType, field: &str) -> InferredType
+{
+    while !ty.accepts(field) {
+        ty = ty.widened();
+    }
+    ty
+}
+
+/// A typed schema inferred from a CSV file's columns, keyed by header name.
+///
+/// Each entry is `(name, type, nullable)`, where `nullable` is `true` if an empty field was
+/// observed in that column.
+#[derive(Debug, Clone)]
+pub struct Schema
+{
+    pub columns: Vec<(String, InferredType, bool)>,
+}
+
+/// Reads up to `sample_size` rows (or every row, when `sample_size` is `None`) through `reader`
+/// and narrows a per-column type lattice to produce a [`Schema`].
+///
+/// `reader` is rewound to the position it was at before inference once sampling finishes, so
+/// that a subsequent [`CsvReader::with_parser`] (e.g. with a [`DynamicRowParser`]) still sees
+/// every row, including the ones consumed while sampling.
+///
+/// # Examples
+///
+/// ```rust
+/// use {
+///     std::{convert::Infallible, io::Cursor},
+///     csv_miri_bug::{schema::InferredType, CsvReader},
+/// };
+///
+/// const CSV_FILE: &str = "name,age,score\nalice,30,9.5\nbob,25,\n";
+///
+/// let mut reader = CsvReader::new_from_reader(
+///     |_| Ok::<_, Infallible>(()),
+///     Cursor::new(CSV_FILE.as_bytes()),
+///     ',',
+/// ).unwrap();
+///
+/// let schema = csv_miri_bug::schema::infer(&mut reader, None).unwrap();
+/// assert_eq!(
+///     schema.columns,
+///     vec![
+///         ("name".to_string(), InferredType::String, false),
+///         ("age".to_string(), InferredType::I64, false),
+///         ("score".to_string(), InferredType::F64, true),
+///     ],
+/// );
+/// ```
+pub fn infer<R: Read + Seek, H>(reader: &mut CsvReader<R, H>, sample_size: Option<usize>) -> csv::Result<Schema>
+{
+    let headers = reader.headers.clone();
+    let mut types = vec![InferredType::Bool; headers.len()];
+    let mut nullable = vec![false; headers.len()];
+
+    let rewind_to = reader.reader.position().clone();
+
+    for (rows_seen, row) in reader.reader.records().enumerate() {
+        if sample_size.is_some_and(|limit| rows_seen >= limit) {
+            break;
+        }
+        let row = row?;
+        for (idx, field) in row.iter().enumerate().take(types.len()) {
+            if field.is_empty() {
+                nullable[idx] = true;
+            } else {
+                types[idx] = widen_for_field(types[idx], field);
+            }
+        }
+    }
+
+    reader.reader.seek(rewind_to)?;
+
+    let columns = headers.iter()
+        .map(str::to_string)
+        .zip(types)
+        .zip(nullable)
+        .map(|((name, ty), null)| (name, ty, null))
+        .collect();
+    let result = Schema { columns };
+    Ok(result)
+}
+
+/// A dynamically typed field value, parsed according to a [`Schema`]'s [`InferredType`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value
+{
+    Bool(bool),
+    I64(i64),
+    F64(f64),
+    Str(String),
+    Null,
+}
+
+fn parse_field(field: &str, ty: InferredType) -> Value
+{
+    match ty {
+        InferredType::Bool => field.parse().map(Value::Bool).unwrap_or_else(|_| Value::Str(field.to_string())),
+        InferredType::I64 => field.parse().map(Value::I64).unwrap_or_else(|_| Value::Str(field.to_string())),
+        InferredType::F64 => field.parse().map(Value::F64).unwrap_or_else(|_| Value::Str(field.to_string())),
+        InferredType::String => Value::Str(field.to_string()),
+    }
+}
+
+/// [`CsvRowParser`] built from a [`Schema`] that parses each field into a [`Value`] according to
+/// its inferred type, letting callers load arbitrary unknown CSVs into a typed, columnar-friendly
+/// representation without writing a bespoke `FromRow` struct and `HeaderIndexer` up front.
+///
+/// Column order is assumed to match `schema`'s, so use it with a [`CsvReader`] built with a
+/// trivial `()` header indexer.
+///
+/// # Examples
+///
+/// ```rust
+/// use {
+///     std::{convert::Infallible, io::Cursor},
+///     csv_miri_bug::{schema::{DynamicRowParser, Value}, CsvReader},
+/// };
+///
+/// const CSV_FILE: &str = "name,age\nalice,30\nbob,25\n";
+///
+/// let mut reader = CsvReader::new_from_reader(
+///     |_| Ok::<_, Infallible>(()),
+///     Cursor::new(CSV_FILE.as_bytes()),
+///     ',',
+/// ).unwrap();
+/// let schema = csv_miri_bug::schema::infer(&mut reader, None).unwrap();
+/// let mut reader = reader.with_parser(DynamicRowParser::new(schema));
+///
+/// assert_eq!(
+///     reader.next().unwrap().unwrap(),
+///     vec![Value::Str("alice".to_string()), Value::I64(30)],
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct DynamicRowParser
+{
+    schema: Schema,
+}
+
+impl DynamicRowParser
+{
+    /// Creates a new [`DynamicRowParser`] from an inferred `schema`.
+    pub fn new(schema: Schema) -> Self
+    {
+        Self { schema }
+    }
+}
+
+impl CsvRowParser for DynamicRowParser
+{
+    type HeaderIndexer = ();
+    type R = Vec<Value>;
+    type E = std::convert::Infallible;
+
+    fn parse_row(
+        &mut self,
+        _header_indexer: &Self::HeaderIndexer,
+        row: StringRecord) -> Result<Self::R, Self::E>
+    {
+        let result = row.iter()
+            .zip(self.schema.columns.iter())
+            .map(|(field, (_, ty, nullable))| {
+                if field.is_empty() && *nullable {
+                    Value::Null
+                } else {
+                    parse_field(field, *ty)
+                }
+            })
+            .collect();
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use {
+        super::*,
+        crate::CsvReader,
+        std::io::Cursor,
+    };
+
+    const CSV_FILE: &str = "name,age,score\nalice,30,9.5\nbob,25,\n";
+
+    #[test]
+    pub fn infer_widens_types_and_marks_nullable_columns()
+    {
+        let mut reader = CsvReader::new_from_reader(
+            |_| Ok::<_, std::convert::Infallible>(()),
+            Cursor::new(CSV_FILE.as_bytes()),
+            ',',
+        )
+            .unwrap();
+
+        let schema = infer(&mut reader, None).unwrap();
+
+        assert_eq!(
+            schema.columns,
+            vec![
+                ("name".to_string(), InferredType::String, false),
+                ("age".to_string(), InferredType::I64, false),
+                ("score".to_string(), InferredType::F64, true),
+            ],
+        );
+    }
+
+    #[test]
+    pub fn infer_rewinds_reader_so_rows_are_still_readable()
+    {
+        let mut reader = CsvReader::new_from_reader(
+            |_| Ok::<_, std::convert::Infallible>(()),
+            Cursor::new(CSV_FILE.as_bytes()),
+            ',',
+        )
+            .unwrap();
+
+        let schema = infer(&mut reader, None).unwrap();
+        let mut reader = reader.with_parser(DynamicRowParser::new(schema));
+
+        assert_eq!(
+            reader.next().unwrap().unwrap(),
+            vec![Value::Str("alice".to_string()), Value::I64(30), Value::F64(9.5)],
+        );
+        assert_eq!(
+            reader.next().unwrap().unwrap(),
+            vec![Value::Str("bob".to_string()), Value::I64(25), Value::Null],
+        );
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    pub fn infer_respects_sample_size()
+    {
+        const CSV_FILE: &str = "n\n1\n2\nnot_a_number\n";
+
+        let mut reader = CsvReader::new_from_reader(
+            |_| Ok::<_, std::convert::Infallible>(()),
+            Cursor::new(CSV_FILE.as_bytes()),
+            ',',
+        )
+            .unwrap();
+
+        let schema = infer(&mut reader, Some(2)).unwrap();
+
+        assert_eq!(schema.columns, vec![("n".to_string(), InferredType::I64, false)]);
+    }
+}